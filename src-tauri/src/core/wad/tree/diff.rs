@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+
+use super::{WadTree, WadTreeItem, WadTreeItemKey, WadTreeParent, WadTreePathable};
+
+/// Classification of a single path when comparing two [`WadTree`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WadTreeDiffStatus {
+    /// Present in the other tree but not in this one.
+    Added,
+    /// Present in this tree but not in the other one.
+    Removed,
+    /// Present in both trees, but the underlying chunk differs.
+    Modified,
+    /// Present in both trees with an identical underlying chunk.
+    Unchanged,
+}
+
+/// A single entry of a [`WadTreeDiff`], mirroring the shape of the tree it was computed from.
+#[derive(Debug)]
+pub struct WadTreeDiffEntry {
+    pub status: WadTreeDiffStatus,
+    pub path: Arc<str>,
+    pub children: IndexMap<WadTreeItemKey, WadTreeDiffEntry>,
+}
+
+/// The result of diffing two [`WadTree`]s, produced by [`WadTree::diff`].
+#[derive(Debug, Default)]
+pub struct WadTreeDiff {
+    pub entries: IndexMap<WadTreeItemKey, WadTreeDiffEntry>,
+    pub added_paths: Vec<Arc<str>>,
+    pub removed_paths: Vec<Arc<str>>,
+    pub modified_paths: Vec<Arc<str>>,
+}
+
+impl WadTree {
+    /// Compares `self` against `other` (e.g. a base game WAD against a patched one) and
+    /// classifies every path as [`WadTreeDiffStatus::Added`], [`WadTreeDiffStatus::Removed`],
+    /// [`WadTreeDiffStatus::Modified`] or [`WadTreeDiffStatus::Unchanged`].
+    ///
+    /// Items are matched across trees by [`WadTreeItemKey`]; matched file items are compared by
+    /// their underlying chunk checksum and compressed size, and matched directories are recursed
+    /// into so child changes roll up into their parent.
+    pub fn diff(&self, other: &WadTree) -> WadTreeDiff {
+        let mut diff = WadTreeDiff::default();
+
+        diff.entries = diff_items(
+            self.items(),
+            other.items(),
+            &mut diff.added_paths,
+            &mut diff.removed_paths,
+            &mut diff.modified_paths,
+        );
+
+        diff
+    }
+}
+
+fn diff_items(
+    base: &IndexMap<WadTreeItemKey, WadTreeItem>,
+    other: &IndexMap<WadTreeItemKey, WadTreeItem>,
+    added_paths: &mut Vec<Arc<str>>,
+    removed_paths: &mut Vec<Arc<str>>,
+    modified_paths: &mut Vec<Arc<str>>,
+) -> IndexMap<WadTreeItemKey, WadTreeDiffEntry> {
+    let mut entries = IndexMap::default();
+
+    for (key, base_item) in base {
+        let Some(other_item) = other.get(key) else {
+            entries.insert(
+                *key,
+                whole_subtree_entry(base_item, WadTreeDiffStatus::Removed, removed_paths),
+            );
+            continue;
+        };
+
+        entries.insert(
+            *key,
+            diff_matched_item(base_item, other_item, added_paths, removed_paths, modified_paths),
+        );
+    }
+
+    for (key, other_item) in other {
+        if base.contains_key(key) {
+            continue;
+        }
+
+        entries.insert(
+            *key,
+            whole_subtree_entry(other_item, WadTreeDiffStatus::Added, added_paths),
+        );
+    }
+
+    entries
+}
+
+/// Builds a [`WadTreeDiffEntry`] for an item that exists only on one side of the diff (wholly
+/// added or wholly removed), recursing into directories so every descendant file is recorded in
+/// `paths` and given its own entry, matching what [`diff_matched_item`] does for matched
+/// directories.
+fn whole_subtree_entry(
+    item: &WadTreeItem,
+    status: WadTreeDiffStatus,
+    paths: &mut Vec<Arc<str>>,
+) -> WadTreeDiffEntry {
+    let path = item.path();
+
+    let children = match item {
+        WadTreeItem::Directory(dir) => dir
+            .items()
+            .iter()
+            .map(|(key, child)| (*key, whole_subtree_entry(child, status, paths)))
+            .collect(),
+        WadTreeItem::File(_) => {
+            paths.push(path.clone());
+            IndexMap::default()
+        }
+    };
+
+    WadTreeDiffEntry {
+        status,
+        path,
+        children,
+    }
+}
+
+fn diff_matched_item(
+    base_item: &WadTreeItem,
+    other_item: &WadTreeItem,
+    added_paths: &mut Vec<Arc<str>>,
+    removed_paths: &mut Vec<Arc<str>>,
+    modified_paths: &mut Vec<Arc<str>>,
+) -> WadTreeDiffEntry {
+    let path = base_item.path();
+
+    match (base_item, other_item) {
+        (WadTreeItem::Directory(base_dir), WadTreeItem::Directory(other_dir)) => {
+            let children = diff_items(
+                base_dir.items(),
+                other_dir.items(),
+                added_paths,
+                removed_paths,
+                modified_paths,
+            );
+
+            let status = if children
+                .values()
+                .all(|entry| entry.status == WadTreeDiffStatus::Unchanged)
+            {
+                WadTreeDiffStatus::Unchanged
+            } else {
+                WadTreeDiffStatus::Modified
+            };
+
+            WadTreeDiffEntry {
+                status,
+                path,
+                children,
+            }
+        }
+        (WadTreeItem::File(base_file), WadTreeItem::File(other_file)) => {
+            let base_chunk = base_file.chunk();
+            let other_chunk = other_file.chunk();
+
+            let status = if base_chunk.checksum() == other_chunk.checksum()
+                && base_chunk.compressed_size() == other_chunk.compressed_size()
+            {
+                WadTreeDiffStatus::Unchanged
+            } else {
+                modified_paths.push(path.clone());
+                WadTreeDiffStatus::Modified
+            };
+
+            WadTreeDiffEntry {
+                status,
+                path,
+                children: IndexMap::default(),
+            }
+        }
+        // A directory was replaced by a file (or vice-versa) at the same path; every file of the
+        // old item is recorded in `removed_paths` and every file of the new one in `added_paths`,
+        // recursing into either side. The entry itself is `Modified` and has no children.
+        _ => {
+            whole_subtree_entry(base_item, WadTreeDiffStatus::Removed, removed_paths);
+            whole_subtree_entry(other_item, WadTreeDiffStatus::Added, added_paths);
+
+            WadTreeDiffEntry {
+                status: WadTreeDiffStatus::Modified,
+                path,
+                children: IndexMap::default(),
+            }
+        }
+    }
+}
@@ -10,10 +10,18 @@ use thiserror::Error;
 use tracing::info;
 use uuid::Uuid;
 
+mod diff;
 mod item;
+mod query;
+mod stats;
 mod utils;
+mod verify;
 
+pub use diff::*;
 pub use item::*;
+pub use query::*;
+pub use stats::*;
+pub use verify::*;
 
 use crate::state::WadHashtable;
 
@@ -0,0 +1,133 @@
+use indexmap::IndexMap;
+
+use super::{WadTree, WadTreeItem, WadTreeItemKey, WadTreeParent, WadTreePathable};
+
+/// A single slash-delimited segment of a parsed glob pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum WadTreeGlobSegment<'a> {
+    /// A single path segment pattern, e.g. `*`, `skins`, or `*.dds`: the literal runs on either
+    /// side of each `*` within the segment, matched against one whole path segment.
+    Pattern(Vec<&'a str>),
+    /// `**`, matching zero or more path segments.
+    RecursiveWildcard,
+}
+
+fn parse_glob_pattern(pattern: &str) -> Vec<WadTreeGlobSegment<'_>> {
+    pattern
+        .split('/')
+        .map(|segment| match segment {
+            "**" => WadTreeGlobSegment::RecursiveWildcard,
+            segment => WadTreeGlobSegment::Pattern(segment.split('*').collect()),
+        })
+        .collect()
+}
+
+/// Matches a single path segment `name` against a [`WadTreeGlobSegment::Pattern`]'s literal runs,
+/// treating each gap between runs as a `*` that can match any (possibly empty) run of characters.
+fn segment_matches(name: &str, parts: &[&str]) -> bool {
+    let Some((first, rest)) = parts.split_first() else {
+        return name.is_empty();
+    };
+
+    if parts.len() == 1 {
+        return name == *first;
+    }
+
+    let Some(mut remainder) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let (middle, last) = rest.split_at(rest.len() - 1);
+    let last = last[0];
+
+    let Some(before_last) = remainder.strip_suffix(last) else {
+        return false;
+    };
+    remainder = before_last;
+
+    for part in middle {
+        if part.is_empty() {
+            continue;
+        }
+
+        let Some(index) = remainder.find(part) else {
+            return false;
+        };
+        remainder = &remainder[index + part.len()..];
+    }
+
+    true
+}
+
+impl WadTree {
+    /// Resolves a slash-delimited glob pattern (e.g.
+    /// `data/characters/*/skins/**/*.dds`) against the tree's resolved chunk paths and returns
+    /// every matching [`WadTreeItem`].
+    ///
+    /// Within a path segment, `*` matches any run of characters (so `*.dds` matches any file
+    /// whose name ends in `.dds`); `**` matches zero or more whole segments. Subtrees that cannot
+    /// possibly match are skipped so large WADs resolve quickly.
+    pub fn resolve_path(&self, pattern: &str) -> Vec<&WadTreeItem> {
+        let segments = parse_glob_pattern(pattern);
+        let mut matches = vec![];
+
+        resolve_glob(self.items(), &segments, &mut matches);
+
+        matches
+    }
+}
+
+fn resolve_glob<'a>(
+    items: &'a IndexMap<WadTreeItemKey, WadTreeItem>,
+    segments: &[WadTreeGlobSegment],
+    matches: &mut Vec<&'a WadTreeItem>,
+) {
+    let Some((segment, rest)) = segments.split_first() else {
+        return;
+    };
+
+    match segment {
+        WadTreeGlobSegment::Pattern(parts) => {
+            for item in items.values() {
+                if segment_matches(item.name().as_ref(), parts) {
+                    match_item(item, rest, matches);
+                }
+            }
+        }
+        WadTreeGlobSegment::RecursiveWildcard if rest.is_empty() => {
+            // A trailing `**` matches every item at and below this level.
+            for item in items.values() {
+                matches.push(item);
+
+                if let WadTreeItem::Directory(dir) = item {
+                    resolve_glob(dir.items(), segments, matches);
+                }
+            }
+        }
+        WadTreeGlobSegment::RecursiveWildcard => {
+            // `**` may match zero segments, so try the remainder of the pattern here too.
+            resolve_glob(items, rest, matches);
+
+            for item in items.values() {
+                if let WadTreeItem::Directory(dir) = item {
+                    resolve_glob(dir.items(), segments, matches);
+                }
+            }
+        }
+    }
+}
+
+fn match_item<'a>(
+    item: &'a WadTreeItem,
+    rest: &[WadTreeGlobSegment],
+    matches: &mut Vec<&'a WadTreeItem>,
+) {
+    if rest.is_empty() {
+        matches.push(item);
+        return;
+    }
+
+    if let WadTreeItem::Directory(dir) = item {
+        resolve_glob(dir.items(), rest, matches);
+    }
+}
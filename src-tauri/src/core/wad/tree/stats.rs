@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+
+use super::{WadTree, WadTreeItem, WadTreeItemKey, WadTreeParent, WadTreePathable};
+
+/// Size aggregates for a single directory (or the whole tree).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WadTreeSizeStats {
+    pub file_count: u64,
+    pub compressed_size: u64,
+    pub uncompressed_size: u64,
+}
+
+impl WadTreeSizeStats {
+    /// Ratio of `compressed_size` to `uncompressed_size`, or `1.0` for an empty directory.
+    pub fn compression_ratio(&self) -> f64 {
+        if self.uncompressed_size == 0 {
+            return 1.0;
+        }
+
+        self.compressed_size as f64 / self.uncompressed_size as f64
+    }
+}
+
+/// A group of paths whose underlying chunks share an identical checksum.
+#[derive(Debug)]
+pub struct WadTreeDuplicateGroup {
+    pub checksum: u64,
+    pub chunk_size: u64,
+    pub paths: Vec<Arc<str>>,
+}
+
+impl WadTreeDuplicateGroup {
+    /// Bytes that could be reclaimed if every duplicate past the first were dropped.
+    pub fn wasted_bytes(&self) -> u64 {
+        self.chunk_size * (self.paths.len() as u64 - 1)
+    }
+}
+
+/// Aggregated size and duplication statistics over a [`WadTree`], produced by
+/// [`WadTree::statistics`].
+#[derive(Debug)]
+pub struct WadTreeStatistics {
+    pub total: WadTreeSizeStats,
+    pub directories: IndexMap<Arc<str>, WadTreeSizeStats>,
+    pub duplicates: Vec<WadTreeDuplicateGroup>,
+}
+
+impl WadTreeStatistics {
+    /// Total bytes wasted across all duplicate groups.
+    pub fn wasted_bytes(&self) -> u64 {
+        self.duplicates.iter().map(|group| group.wasted_bytes()).sum()
+    }
+}
+
+impl WadTree {
+    /// Produces per-directory and whole-tree size aggregates, and detects duplicate content by
+    /// grouping leaf chunks that share an identical checksum.
+    pub fn statistics(&self) -> WadTreeStatistics {
+        let mut directories = IndexMap::default();
+        let mut duplicates: IndexMap<u64, WadTreeDuplicateGroup> = IndexMap::default();
+
+        let total = collect_directory_stats(self.items(), "", &mut directories, &mut duplicates);
+
+        let duplicates = duplicates
+            .into_values()
+            .filter(|group| group.paths.len() > 1)
+            .collect();
+
+        WadTreeStatistics {
+            total,
+            directories,
+            duplicates,
+        }
+    }
+}
+
+fn collect_directory_stats(
+    items: &IndexMap<WadTreeItemKey, WadTreeItem>,
+    directory_path: &str,
+    directories: &mut IndexMap<Arc<str>, WadTreeSizeStats>,
+    duplicates: &mut IndexMap<u64, WadTreeDuplicateGroup>,
+) -> WadTreeSizeStats {
+    let mut stats = WadTreeSizeStats::default();
+
+    for item in items.values() {
+        match item {
+            WadTreeItem::Directory(dir) => {
+                let child_stats =
+                    collect_directory_stats(dir.items(), &dir.path(), directories, duplicates);
+
+                stats.file_count += child_stats.file_count;
+                stats.compressed_size += child_stats.compressed_size;
+                stats.uncompressed_size += child_stats.uncompressed_size;
+            }
+            WadTreeItem::File(file) => {
+                let chunk = file.chunk();
+
+                stats.file_count += 1;
+                stats.compressed_size += chunk.compressed_size() as u64;
+                stats.uncompressed_size += chunk.uncompressed_size() as u64;
+
+                duplicates
+                    .entry(chunk.checksum())
+                    .or_insert_with(|| WadTreeDuplicateGroup {
+                        checksum: chunk.checksum(),
+                        chunk_size: chunk.compressed_size() as u64,
+                        paths: vec![],
+                    })
+                    .paths
+                    .push(file.path());
+            }
+        }
+    }
+
+    if !directory_path.is_empty() {
+        directories.insert(directory_path.into(), stats);
+    }
+
+    stats
+}
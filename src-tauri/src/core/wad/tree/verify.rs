@@ -0,0 +1,65 @@
+use std::io::{Read, Seek};
+
+use thiserror::Error;
+
+use super::{WadTree, WadTreeItem, WadTreeParent, WadTreePathable};
+use super::super::{Wad, WadError};
+
+#[derive(Error, Debug)]
+pub enum WadIntegrityError {
+    #[error("broken chunk (path: {path})")]
+    BrokenChunk {
+        path: String,
+        #[source]
+        source: WadError,
+    },
+
+    #[error("missing chunk data (path: {path})")]
+    MissingChunkData { path: String },
+}
+
+impl WadTree {
+    /// Walks every file item in the tree and validates its backing chunk: re-reads the chunk
+    /// bytes, confirms the stored checksum matches a recomputed hash, confirms the declared
+    /// uncompressed size matches what decompression yields, and confirms the compression type is
+    /// decodable.
+    ///
+    /// Returns one [`WadIntegrityError`] per damaged or unreadable chunk, so callers can report
+    /// every problem in a single pass instead of bailing out at the first one.
+    pub fn verify<TSource>(&self, wad: &Wad<TSource>) -> Vec<WadIntegrityError>
+    where
+        TSource: Read + Seek,
+    {
+        let mut errors = vec![];
+
+        self.traverse_items(&mut |item| {
+            let WadTreeItem::File(file) = item else {
+                return;
+            };
+
+            let path = file.path().to_string();
+            let chunk = file.chunk();
+
+            match wad.load_chunk_decompressed(chunk) {
+                Ok(data) => {
+                    if data.len() as u64 != chunk.uncompressed_size() as u64
+                        || xxhash_rust::xxh3::xxh3_64(&data) != chunk.checksum()
+                    {
+                        errors.push(WadIntegrityError::BrokenChunk {
+                            path,
+                            source: WadError::ChunkChecksumMismatch,
+                        });
+                    }
+                }
+                Err(WadError::ChunkNotFound { .. }) => {
+                    errors.push(WadIntegrityError::MissingChunkData { path });
+                }
+                Err(source) => {
+                    errors.push(WadIntegrityError::BrokenChunk { path, source });
+                }
+            }
+        });
+
+        errors
+    }
+}